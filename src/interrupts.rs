@@ -1,6 +1,6 @@
 use hidapi::HidDevice;
 
-use crate::{MclSsg, MclSsgResult};
+use crate::{Frequency, MclSsg, MclSsgResult, Power, Transport};
 const SEND_PACKET_LEN: usize = 64;
 
 #[repr(u8)]
@@ -14,6 +14,9 @@ enum InterruptCode {
     SetFreqAndPower = 103,
     SetRfPowerOnOff = 104,
     GetGeneratorOutputStatus = 105,
+    SetFreqAndPowerXg = 110,
+    SetOutputMode = 111,
+    GetOutputMode = 112,
 }
 
 macro_rules! pack_with_interrupt {
@@ -24,22 +27,24 @@ macro_rules! pack_with_interrupt {
     }};
 }
 
-/// Write then read, validating the return interrupt code
-fn write_read(dev: &HidDevice, bytes: &mut [u8]) -> MclSsgResult<()> {
-    let code = bytes[0];
-    dev.write(bytes)?;
-    dev.read(bytes)?;
-    if bytes[0] != code {
-        Err(super::Error::BadHidRead)
-    } else {
-        Ok(())
+impl Transport for HidDevice {
+    /// Write then read, validating the return interrupt code
+    fn write_read(&self, bytes: &mut [u8]) -> MclSsgResult<()> {
+        let code = bytes[0];
+        self.write(bytes)?;
+        self.read(bytes)?;
+        if bytes[0] != code {
+            Err(super::Error::BadHidRead)
+        } else {
+            Ok(())
+        }
     }
 }
 
 /// Internal method to validate device type
-pub(crate) fn model_name(dev: &HidDevice) -> MclSsgResult<String> {
+pub(crate) fn model_name<D: Transport>(dev: &D) -> MclSsgResult<String> {
     let mut bytes = pack_with_interrupt!(DeviceModelName);
-    write_read(dev, &mut bytes)?;
+    dev.write_read(&mut bytes)?;
     let null_idx = bytes
         .iter()
         .position(|x| *x == 0)
@@ -47,34 +52,34 @@ pub(crate) fn model_name(dev: &HidDevice) -> MclSsgResult<String> {
     Ok(String::from_utf8(bytes[1..null_idx].to_vec())?)
 }
 
-pub(crate) fn min_freq(dev: &HidDevice) -> MclSsgResult<u64> {
+pub(crate) fn min_freq<D: Transport>(dev: &D) -> MclSsgResult<u64> {
     let mut bytes = pack_with_interrupt!(GeneratorMinimumFrequency);
-    write_read(dev, &mut bytes)?;
+    dev.write_read(&mut bytes)?;
     let mut freq_bytes = [0u8; 8];
     freq_bytes[4..].clone_from_slice(&bytes[1..5]);
     let freq = u64::from_be_bytes(freq_bytes);
     Ok(freq)
 }
 
-pub(crate) fn max_freq(dev: &HidDevice) -> MclSsgResult<u64> {
+pub(crate) fn max_freq<D: Transport>(dev: &D) -> MclSsgResult<u64> {
     let mut bytes = pack_with_interrupt!(GeneratorMaximumFrequency);
-    write_read(dev, &mut bytes)?;
+    dev.write_read(&mut bytes)?;
     let mut freq_bytes = [0u8; 8];
     freq_bytes[3..].clone_from_slice(&bytes[1..6]);
     let freq = u64::from_be_bytes(freq_bytes);
     Ok(freq)
 }
 
-pub(crate) fn min_power(dev: &HidDevice) -> MclSsgResult<f32> {
+pub(crate) fn min_power<D: Transport>(dev: &D) -> MclSsgResult<f32> {
     let mut bytes = pack_with_interrupt!(GeneratorMinimumPower);
-    write_read(dev, &mut bytes)?;
+    dev.write_read(&mut bytes)?;
     let power = bytes_to_power(&bytes[1..4]);
     Ok(power)
 }
 
-pub(crate) fn max_power(dev: &HidDevice) -> MclSsgResult<f32> {
+pub(crate) fn max_power<D: Transport>(dev: &D) -> MclSsgResult<f32> {
     let mut bytes = pack_with_interrupt!(GeneratorMaximumPower);
-    write_read(dev, &mut bytes)?;
+    dev.write_read(&mut bytes)?;
     let power = bytes_to_power(&bytes[1..4]);
     Ok(power)
 }
@@ -94,7 +99,7 @@ fn power_to_bytes(power: f32) -> Vec<u8> {
 }
 
 /// Implementations for the generic SSG
-impl<T> MclSsg<T> {
+impl<T, D: Transport> MclSsg<T, D> {
     /// Get the connected generator's model name
     pub fn get_model_name(&self) -> MclSsgResult<String> {
         model_name(&self.dev)
@@ -103,7 +108,7 @@ impl<T> MclSsg<T> {
     /// Get the connected generator's serial number
     pub fn get_serial_number(&self) -> MclSsgResult<String> {
         let mut bytes = pack_with_interrupt!(DeviceSerialNumber);
-        write_read(&self.dev, &mut bytes)?;
+        self.dev.write_read(&mut bytes)?;
         let null_idx = bytes
             .iter()
             .position(|x| *x == 0)
@@ -114,7 +119,7 @@ impl<T> MclSsg<T> {
     /// Get the output status of the signal generator
     pub fn get_status(&self) -> MclSsgResult<super::Status> {
         let mut bytes = pack_with_interrupt!(GetGeneratorOutputStatus);
-        write_read(&self.dev, &mut bytes)?;
+        self.dev.write_read(&mut bytes)?;
         let enabled = bytes[1] != 0;
         let locked = bytes[2] != 0;
         let mut freq_bytes = [0u8; 8];
@@ -124,8 +129,8 @@ impl<T> MclSsg<T> {
         Ok(super::Status {
             enabled,
             locked,
-            freq,
-            power,
+            freq: Frequency::from_hz(freq),
+            power: Power::from_dbm(power),
         })
     }
 
@@ -133,38 +138,38 @@ impl<T> MclSsg<T> {
     pub fn set_rf_power_on(&self, enabled: bool) -> MclSsgResult<()> {
         let mut bytes = pack_with_interrupt!(SetRfPowerOnOff);
         bytes[1] = enabled as u8;
-        write_read(&self.dev, &mut bytes)?;
+        self.dev.write_read(&mut bytes)?;
         Ok(())
     }
 
-    /// Get the minimum supported frequency in Hz
-    pub fn get_min_freq(&self) -> u64 {
+    /// Get the minimum supported frequency
+    pub fn get_min_freq(&self) -> Frequency {
         self.min_freq
     }
 
-    /// Get the maximum supported frequency in Hz
-    pub fn get_max_freq(&self) -> u64 {
+    /// Get the maximum supported frequency
+    pub fn get_max_freq(&self) -> Frequency {
         self.max_freq
     }
 
-    /// Get the minimum supported power in dBm
-    pub fn get_min_power(&self) -> f32 {
+    /// Get the minimum supported power
+    pub fn get_min_power(&self) -> Power {
         self.min_power
     }
 
-    /// Get the maximum supported power in dBm
-    pub fn get_max_power(&self) -> f32 {
+    /// Get the maximum supported power
+    pub fn get_max_power(&self) -> Power {
         self.max_power
     }
 }
 
 /// Implementations for the SSG-6000 series
-impl MclSsg<super::Ssg6000> {
-    /// Set the RF output frequency in Hz, power in dBm, and the trigger out function
+impl<D: Transport> MclSsg<super::Ssg6000, D> {
+    /// Set the RF output frequency, power, and the trigger out function
     pub fn set_frequency_power_trigger(
         &self,
-        freq: u64,
-        power: f32,
+        freq: Frequency,
+        power: Power,
         trigger: bool,
     ) -> MclSsgResult<()> {
         if freq < self.min_freq
@@ -175,12 +180,219 @@ impl MclSsg<super::Ssg6000> {
             return Err(super::Error::OutOfRange);
         }
         let mut bytes = pack_with_interrupt!(SetFreqAndPower);
-        let freq_bytes = freq.to_be_bytes();
+        let freq_bytes = freq.as_hz().to_be_bytes();
         bytes[1..6].clone_from_slice(&freq_bytes[3..]);
-        let power_bytes = power_to_bytes(power);
+        let power_bytes = power_to_bytes(power.as_dbm());
         bytes[6..9].clone_from_slice(&power_bytes);
         bytes[9] = trigger as u8;
-        write_read(&self.dev, &mut bytes)?;
+        self.dev.write_read(&mut bytes)?;
+        Ok(())
+    }
+}
+
+/// Implementations for the SSG-XG series
+impl<D: Transport> MclSsg<super::SsgXg, D> {
+    /// Set the RF output frequency (at the XG's finer, sub-Hz resolution), power,
+    /// and the trigger out function
+    pub fn set_frequency_power_trigger(
+        &self,
+        freq: Frequency,
+        power: Power,
+        trigger: bool,
+    ) -> MclSsgResult<()> {
+        if freq < self.min_freq
+            || freq > self.max_freq
+            || power < self.min_power
+            || power > self.max_power
+        {
+            return Err(super::Error::OutOfRange);
+        }
+        let mut bytes = pack_with_interrupt!(SetFreqAndPowerXg);
+        let (freq_hz, freq_millihz) = freq.as_hz_millihz();
+        let freq_bytes = freq_hz.to_be_bytes();
+        bytes[1..6].clone_from_slice(&freq_bytes[3..]);
+        bytes[6..8].clone_from_slice(&freq_millihz.to_be_bytes());
+        let power_bytes = power_to_bytes(power.as_dbm());
+        bytes[8..11].clone_from_slice(&power_bytes);
+        bytes[11] = trigger as u8;
+        self.dev.write_read(&mut bytes)?;
+        Ok(())
+    }
+
+    /// Set the RF output mode (continuous-wave or pulse-modulated)
+    pub fn set_output_mode(&self, mode: super::OutputMode) -> MclSsgResult<()> {
+        let mut bytes = pack_with_interrupt!(SetOutputMode);
+        bytes[1] = mode as u8;
+        self.dev.write_read(&mut bytes)?;
         Ok(())
     }
+
+    /// Get the current RF output mode
+    pub fn get_output_mode(&self) -> MclSsgResult<super::OutputMode> {
+        let mut bytes = pack_with_interrupt!(GetOutputMode);
+        self.dev.write_read(&mut bytes)?;
+        match bytes[1] {
+            0 => Ok(super::OutputMode::Continuous),
+            1 => Ok(super::OutputMode::Pulsed),
+            _ => Err(super::Error::BadHidRead),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, collections::VecDeque};
+
+    /// A scripted transport that replays pre-recorded responses, so the protocol
+    /// encode/decode logic can be exercised without real hardware
+    struct MockTransport {
+        responses: RefCell<VecDeque<[u8; SEND_PACKET_LEN]>>,
+    }
+
+    impl MockTransport {
+        fn new(responses: Vec<[u8; SEND_PACKET_LEN]>) -> Self {
+            Self {
+                responses: RefCell::new(responses.into()),
+            }
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn write_read(&self, bytes: &mut [u8]) -> MclSsgResult<()> {
+            let response = self
+                .responses
+                .borrow_mut()
+                .pop_front()
+                .expect("no scripted response left");
+            bytes.copy_from_slice(&response);
+            Ok(())
+        }
+    }
+
+    /// Build a scripted response packet for `code` with `body` starting at byte 1
+    fn response(code: InterruptCode, body: &[u8]) -> [u8; SEND_PACKET_LEN] {
+        let mut bytes = [0u8; SEND_PACKET_LEN];
+        bytes[0] = code as u8;
+        bytes[1..1 + body.len()].clone_from_slice(body);
+        bytes
+    }
+
+    #[test]
+    fn power_round_trips_through_bytes() {
+        for power in [-30.0_f32, -0.5, 0.0, 12.25, 20.0] {
+            let bytes = power_to_bytes(power);
+            assert!((bytes_to_power(&bytes) - power).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn model_name_parses_null_terminated_string() {
+        let dev = MockTransport::new(vec![response(
+            InterruptCode::DeviceModelName,
+            b"SSG-6000-4\0",
+        )]);
+        assert_eq!(model_name(&dev).unwrap(), "SSG-6000-4");
+    }
+
+    #[test]
+    fn min_freq_decodes_be_bytes() {
+        let dev = MockTransport::new(vec![response(
+            InterruptCode::GeneratorMinimumFrequency,
+            &100_000u32.to_be_bytes(),
+        )]);
+        assert_eq!(min_freq(&dev).unwrap(), 100_000);
+    }
+
+    #[test]
+    fn max_freq_decodes_be_bytes() {
+        let dev = MockTransport::new(vec![response(
+            InterruptCode::GeneratorMaximumFrequency,
+            &6_000_000_000u64.to_be_bytes()[3..],
+        )]);
+        assert_eq!(max_freq(&dev).unwrap(), 6_000_000_000);
+    }
+
+    #[test]
+    fn min_max_power_decode_through_bytes_to_power() {
+        let min = MockTransport::new(vec![response(
+            InterruptCode::GeneratorMinimumPower,
+            &power_to_bytes(-20.0),
+        )]);
+        assert_eq!(min_power(&min).unwrap(), -20.0);
+
+        let max = MockTransport::new(vec![response(
+            InterruptCode::GeneratorMaximumPower,
+            &power_to_bytes(20.0),
+        )]);
+        assert_eq!(max_power(&max).unwrap(), 20.0);
+    }
+
+    /// The responses `from_transport` expects, in order: model name, min/max frequency,
+    /// min/max power, followed by whatever `extra` responses a test wants to script next
+    fn mock_ssg6000(extra: Vec<[u8; SEND_PACKET_LEN]>) -> MclSsg<crate::Ssg6000, MockTransport> {
+        let mut responses = vec![
+            response(InterruptCode::DeviceModelName, b"SSG-6000-4\0"),
+            response(
+                InterruptCode::GeneratorMinimumFrequency,
+                &100_000u32.to_be_bytes(),
+            ),
+            response(
+                InterruptCode::GeneratorMaximumFrequency,
+                &6_000_000_000u64.to_be_bytes()[3..],
+            ),
+            response(InterruptCode::GeneratorMinimumPower, &power_to_bytes(-20.0)),
+            response(InterruptCode::GeneratorMaximumPower, &power_to_bytes(20.0)),
+        ];
+        responses.extend(extra);
+        let dev = MockTransport::new(responses);
+        crate::MclSsg::<crate::Ssg6000, MockTransport>::from_transport(dev).unwrap()
+    }
+
+    #[test]
+    fn from_transport_rejects_mismatched_model() {
+        let dev = MockTransport::new(vec![
+            response(InterruptCode::DeviceModelName, b"SSG-XG-1\0"),
+            response(
+                InterruptCode::GeneratorMinimumFrequency,
+                &100_000u32.to_be_bytes(),
+            ),
+            response(
+                InterruptCode::GeneratorMaximumFrequency,
+                &6_000_000_000u64.to_be_bytes()[3..],
+            ),
+            response(InterruptCode::GeneratorMinimumPower, &power_to_bytes(-20.0)),
+            response(InterruptCode::GeneratorMaximumPower, &power_to_bytes(20.0)),
+        ]);
+        let result = crate::MclSsg::<crate::Ssg6000, MockTransport>::from_transport(dev);
+        assert!(matches!(result, Err(crate::Error::WrongDevice)));
+    }
+
+    #[test]
+    fn get_status_decodes_all_fields() {
+        let mut status = response(InterruptCode::GetGeneratorOutputStatus, &[]);
+        status[1] = 1;
+        status[2] = 0;
+        let freq_bytes = 2_500_000_000u64.to_be_bytes();
+        status[3..8].clone_from_slice(&freq_bytes[3..]);
+        status[8..11].clone_from_slice(&power_to_bytes(5.5));
+
+        let ssg = mock_ssg6000(vec![status]);
+        let status = ssg.get_status().unwrap();
+        assert!(status.enabled);
+        assert!(!status.locked);
+        assert_eq!(status.freq.as_hz(), 2_500_000_000);
+        assert!((status.power.as_dbm() - 5.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn set_frequency_power_trigger_rejects_out_of_range() {
+        let ssg = mock_ssg6000(vec![]);
+        let result = ssg.set_frequency_power_trigger(
+            Frequency::from_hz(50_000),
+            Power::from_dbm(0.0),
+            false,
+        );
+        assert!(matches!(result, Err(crate::Error::OutOfRange)));
+    }
 }
@@ -1,8 +1,16 @@
 use hidapi::{HidDevice, HidError};
 use interrupts::{max_freq, max_power, min_freq, min_power};
-use std::{marker::PhantomData, string::FromUtf8Error};
+use std::{
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    string::FromUtf8Error,
+};
 
 mod interrupts;
+mod sweep;
+mod units;
+pub use sweep::{sweep_points, Spacing, SweepConfig};
+pub use units::{Frequency, Power};
 
 // Factory-values for the VID and PID for all SSG devices
 const VID: u16 = 0x20CE;
@@ -27,31 +35,104 @@ mod marker {
     pub struct Ssg6000;
     /// Marker type for the SSG-XG series devices
     pub struct SsgXg;
+
+    /// Associates a marker type with the model-name prefix its firmware reports, so
+    /// construction and enumeration logic can be written once and shared across series
+    pub(crate) trait ModelPrefix {
+        const PREFIX: &'static str;
+    }
+
+    impl ModelPrefix for Ssg6000 {
+        const PREFIX: &'static str = "SSG-6000";
+    }
+
+    impl ModelPrefix for SsgXg {
+        const PREFIX: &'static str = "SSG-XG";
+    }
 }
 pub use marker::*;
 
-pub struct MclSsg<T> {
-    dev: HidDevice,
+/// Abstracts the byte-level HID transport so the packing/parsing logic can be
+/// exercised against a recorded or scripted mock instead of real hardware
+pub trait Transport {
+    /// Write `bytes` to the device, then read the response back into the same buffer
+    fn write_read(&self, bytes: &mut [u8]) -> MclSsgResult<()>;
+}
+
+pub struct MclSsg<T, D = HidDevice> {
+    dev: D,
     model: PhantomData<T>,
-    min_freq: u64,
-    max_freq: u64,
-    min_power: f32,
-    max_power: f32,
+    min_freq: Frequency,
+    max_freq: Frequency,
+    min_power: Power,
+    max_power: Power,
 }
 
 pub type MclSsgResult<T> = Result<T, Error>;
 
-impl MclSsg<Ssg6000> {
+/// Identifies a single connected SSG device, as reported by `hidapi` enumeration
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    /// Serial number reported by the device, if any
+    pub serial_number: Option<String>,
+    /// Model-name string reported by the device, if any
+    pub model_name: Option<String>,
+    /// `hidapi` path that uniquely identifies this device, for use with `open_by_path`
+    pub path: CString,
+}
+
+fn enumerate() -> MclSsgResult<Vec<DeviceInfo>> {
+    let api = hidapi::HidApi::new()?;
+    Ok(api
+        .device_list()
+        .filter(|info| info.vendor_id() == VID && info.product_id() == PID)
+        .map(|info| DeviceInfo {
+            serial_number: info.serial_number().map(str::to_owned),
+            model_name: info.product_string().map(str::to_owned),
+            path: info.path().to_owned(),
+        })
+        .collect())
+}
+
+impl<T: ModelPrefix> MclSsg<T, HidDevice> {
     /// Open an SSG device. If multiple are connected, this may be non-deterministic
     pub fn new() -> MclSsgResult<Self> {
         let api = hidapi::HidApi::new()?;
         let dev = api.open(VID, PID)?;
+        Self::from_transport(dev)
+    }
+
+    /// Enumerate all connected SSG devices, regardless of series, so a specific unit
+    /// can be selected deterministically via `open_by_serial` or `open_by_path`
+    pub fn list() -> MclSsgResult<Vec<DeviceInfo>> {
+        enumerate()
+    }
+
+    /// Open the device with the given serial number
+    pub fn open_by_serial(serial: &str) -> MclSsgResult<Self> {
+        let api = hidapi::HidApi::new()?;
+        let dev = api.open_serial(VID, PID, serial)?;
+        Self::from_transport(dev)
+    }
+
+    /// Open the device at the given `hidapi` path, as returned by `list`
+    pub fn open_by_path(path: &CStr) -> MclSsgResult<Self> {
+        let api = hidapi::HidApi::new()?;
+        let dev = api.open_path(path)?;
+        Self::from_transport(dev)
+    }
+}
+
+impl<T: ModelPrefix, D: Transport> MclSsg<T, D> {
+    /// Validate and wrap an already-open transport, checking the device's reported model
+    /// name against `T::PREFIX`
+    fn from_transport(dev: D) -> MclSsgResult<Self> {
         let model = interrupts::model_name(&dev)?;
-        let min_freq = min_freq(&dev)?;
-        let max_freq = max_freq(&dev)?;
-        let min_power = min_power(&dev)?;
-        let max_power = max_power(&dev)?;
-        if model.starts_with("SSG-6000") {
+        let min_freq = Frequency::from_hz(min_freq(&dev)?);
+        let max_freq = Frequency::from_hz(max_freq(&dev)?);
+        let min_power = Power::from_dbm(min_power(&dev)?);
+        let max_power = Power::from_dbm(max_power(&dev)?);
+        if model.starts_with(T::PREFIX) {
             Ok(Self {
                 dev,
                 model: PhantomData,
@@ -66,14 +147,23 @@ impl MclSsg<Ssg6000> {
     }
 }
 
+/// RF output mode supported by the SSG-XG series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Continuous-wave output
+    Continuous = 0,
+    /// Pulse-modulated output
+    Pulsed = 1,
+}
+
 #[derive(Debug)]
 pub struct Status {
     // Is the RF output enabled
     pub enabled: bool,
     // Is the frequency locked to some reference
     pub locked: bool,
-    // Frequency in Hz
-    pub freq: u64,
-    // Power in dBm
-    pub power: f32,
+    // Output frequency
+    pub freq: Frequency,
+    // Output power
+    pub power: Power,
 }
@@ -0,0 +1,78 @@
+use std::{thread, time::Duration};
+
+use crate::{Error, Frequency, MclSsg, MclSsgResult, Power, Ssg6000, Transport};
+
+/// Frequency spacing used between successive sweep points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spacing {
+    /// Equal Hz steps between `start` and `stop`
+    Linear,
+    /// Equal ratio steps between `start` and `stop`
+    Logarithmic,
+}
+
+/// Configuration for a software-driven frequency/power sweep
+#[derive(Debug, Clone)]
+pub struct SweepConfig {
+    /// Frequency of the first sweep point; a single-point sweep visits only `stop`
+    pub start: Frequency,
+    /// Frequency of the last sweep point
+    pub stop: Frequency,
+    /// Number of points in the sweep, including the endpoints
+    pub points: usize,
+    /// Spacing between successive sweep points
+    pub spacing: Spacing,
+    /// Output power held constant across the sweep
+    pub power: Power,
+    /// Time to hold each point before advancing to the next
+    pub dwell: Duration,
+    /// If set, the trigger line is pulsed high then low at each point
+    pub toggle_trigger_per_point: bool,
+}
+
+/// Compute the `(frequency, power)` pair visited at sweep step `i`
+fn sweep_point(cfg: &SweepConfig, i: usize) -> (Frequency, Power) {
+    if cfg.points <= 1 {
+        // A single-point sweep still has to land on the documented endpoint
+        return (cfg.stop, cfg.power);
+    }
+    let start = cfg.start.raw_hz();
+    let stop = cfg.stop.raw_hz();
+    let frac = i as f64 / (cfg.points - 1) as f64;
+    let hz = match cfg.spacing {
+        Spacing::Linear => start + (stop - start) * frac,
+        Spacing::Logarithmic => start * (stop / start).powf(frac),
+    };
+    (Frequency::from_hz(hz.round() as u64), cfg.power)
+}
+
+/// Iterate the `(frequency, power)` pairs a sweep will visit, without driving any hardware
+pub fn sweep_points(cfg: &SweepConfig) -> impl Iterator<Item = (Frequency, Power)> + '_ {
+    (0..cfg.points).map(move |i| sweep_point(cfg, i))
+}
+
+impl<D: Transport> MclSsg<Ssg6000, D> {
+    /// Run a software-driven frequency/power sweep, stepping `set_frequency_power_trigger`
+    /// across `cfg.points` and dwelling at each one. When `toggle_trigger_per_point` is set,
+    /// the trigger line is pulsed high then low at every point so downstream instruments can
+    /// latch each step; the generator is left at `cfg.stop` once the sweep completes.
+    pub fn run_sweep(&self, cfg: &SweepConfig) -> MclSsgResult<()> {
+        if cfg.points == 0 {
+            return Err(Error::OutOfRange);
+        }
+        if cfg.spacing == Spacing::Logarithmic && cfg.start.as_hz() == 0 {
+            return Err(Error::OutOfRange);
+        }
+        for (freq, power) in sweep_points(cfg) {
+            if cfg.toggle_trigger_per_point {
+                self.set_frequency_power_trigger(freq, power, true)?;
+                thread::sleep(cfg.dwell);
+                self.set_frequency_power_trigger(freq, power, false)?;
+            } else {
+                self.set_frequency_power_trigger(freq, power, false)?;
+                thread::sleep(cfg.dwell);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+/// A frequency quantity, so callers can't accidentally pass MHz where Hz is expected
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Frequency(f64);
+
+impl Frequency {
+    /// Construct a frequency from a whole number of Hz
+    pub const fn from_hz(hz: u64) -> Self {
+        Self(hz as f64)
+    }
+
+    /// Construct a frequency from a number of kHz
+    pub fn from_khz(khz: f64) -> Self {
+        Self(khz * 1e3)
+    }
+
+    /// Construct a frequency from a number of MHz
+    pub fn from_mhz(mhz: f64) -> Self {
+        Self(mhz * 1e6)
+    }
+
+    /// Construct a frequency from a number of GHz
+    pub fn from_ghz(ghz: f64) -> Self {
+        Self(ghz * 1e9)
+    }
+
+    /// The frequency in Hz, rounded to the nearest whole Hz
+    pub fn as_hz(&self) -> u64 {
+        self.0.round() as u64
+    }
+
+    /// Integer Hz plus a milli-Hz remainder in `0..1000`, for the SSG-XG's finer resolution.
+    /// Rounds to the nearest milli-Hz, carrying into the Hz part so the remainder can never
+    /// reach 1000.
+    pub(crate) fn as_hz_millihz(&self) -> (u64, u16) {
+        let total_millihz = (self.0 * 1000.0).round() as u64;
+        (total_millihz / 1000, (total_millihz % 1000) as u16)
+    }
+
+    /// The frequency in Hz as an unrounded `f64`, for internal interpolation
+    pub(crate) fn raw_hz(&self) -> f64 {
+        self.0
+    }
+}
+
+/// An RF output power in dBm, so callers can't accidentally pass a raw, unitless `f32`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Power(f32);
+
+impl Power {
+    /// Construct a power from a dBm value
+    pub const fn from_dbm(dbm: f32) -> Self {
+        Self(dbm)
+    }
+
+    /// The power in dBm
+    pub fn as_dbm(&self) -> f32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_hz_millihz_never_reaches_1000() {
+        let freq = Frequency::from_khz(1.0009996);
+        let (hz, millihz) = freq.as_hz_millihz();
+        assert_eq!(hz, 1001);
+        assert_eq!(millihz, 0);
+        assert!(millihz < 1000);
+    }
+}